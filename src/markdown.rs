@@ -3,19 +3,40 @@ use std::error::Error;
 use femark::{process_markdown_to_html, HTMLOutput};
 
 /// parse a markdown source into its optional frontmatter and the HTML string.
-pub fn parse(source: &str) -> Result<(Option<frontmatter::Yaml>, String), Box<dyn Error>> {
+pub fn parse(
+    source: &str,
+    options: pulldown_cmark::Options,
+) -> Result<(Option<frontmatter::Yaml>, String), Box<dyn Error>> {
     let (fm, content) = extract_frontmatter(source)?;
-    let html = md_to_html(content);
+    let html = md_to_html(content, options);
     Ok((fm, html))
 }
 
+/// Like [`parse`], but runs the body through `femark`'s tree-sitter pipeline so
+/// fenced code blocks come back syntax-highlighted. Also returns the generated
+/// table of contents, when the document produced one.
+pub fn parse_highlighted(
+    source: &str,
+) -> Result<(Option<frontmatter::Yaml>, String, Option<String>), Box<dyn Error>> {
+    let (fm, content) = extract_frontmatter(source)?;
+    let HTMLOutput {
+        content: html, toc, ..
+    } = process_markdown_to_html(content)?;
+    let toc = if toc.trim().is_empty() {
+        None
+    } else {
+        Some(toc)
+    };
+    Ok((fm, html, toc))
+}
+
 fn extract_frontmatter(input: &str) -> Result<(Option<frontmatter::Yaml>, &str), Box<dyn Error>> {
     let (fm, content) = frontmatter::parse_and_find_content(input)?;
     Ok((fm, content))
 }
 
-fn md_to_html(s: &str) -> String {
-    let parser = pulldown_cmark::Parser::new(s);
+pub(crate) fn md_to_html(s: &str, options: pulldown_cmark::Options) -> String {
+    let parser = pulldown_cmark::Parser::new_ext(s, options);
     let mut html_output = String::new();
     pulldown_cmark::html::push_html(&mut html_output, parser);
     // let Ok(HTMLOutput { content, .. }) = process_markdown_to_html(s) else {
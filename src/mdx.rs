@@ -1,28 +1,287 @@
 use leptos::{
-    component, html::ElementDescriptor, Children, Fragment, HtmlElement, IntoView, View,
+    component, create_memo, html::ElementDescriptor, provide_context, Callback, Children, Fragment,
+    HtmlElement, IntoView, MaybeSignal, SignalGet, View,
 };
 use regex::Regex;
 use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
 use tl::{HTMLTag, Node};
 
-use crate::markdown::parse;
+use crate::markdown::{md_to_html, parse, parse_highlighted};
+
+/// An error raised while turning a markdown source into a view tree.
+///
+/// Frontmatter and markdown errors are recoverable: `Mdx` keeps rendering the
+/// body and surfaces the error through the optional `on_error` prop instead of
+/// aborting the whole render, which matters in editors where the source is
+/// malformed on almost every keystroke. An HTML parse error leaves no document
+/// to render, so it replaces the output with the `on_error` view (or an inline
+/// placeholder when no `on_error` prop is given).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MdxError {
+    /// The YAML frontmatter block could not be parsed.
+    Frontmatter(String),
+    /// The markdown body could not be converted to HTML.
+    Markdown(String),
+    /// The generated HTML could not be parsed back into a DOM.
+    Html(String),
+}
+
+impl fmt::Display for MdxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MdxError::Frontmatter(msg) => write!(f, "frontmatter error: {msg}"),
+            MdxError::Markdown(msg) => write!(f, "markdown error: {msg}"),
+            MdxError::Html(msg) => write!(f, "html parse error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MdxError {}
+
+/// GitHub-Flavored-Markdown extensions enabled while parsing the body.
+///
+/// The defaults turn on the full GFM set (tables, strikethrough, footnotes,
+/// task lists and heading ids); pass a customized value through the `options`
+/// prop to opt out of individual extensions.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MdxOptions {
+    /// Pipe tables (`| a | b |`).
+    pub tables: bool,
+    /// `~~strikethrough~~`.
+    pub strikethrough: bool,
+    /// `[^1]` footnote references and definitions.
+    pub footnotes: bool,
+    /// `- [ ]` / `- [x]` task list items.
+    pub task_lists: bool,
+    /// `# Heading {#id}` heading attributes.
+    pub heading_attributes: bool,
+}
+
+impl Default for MdxOptions {
+    fn default() -> Self {
+        Self {
+            tables: true,
+            strikethrough: true,
+            footnotes: true,
+            task_lists: true,
+            heading_attributes: true,
+        }
+    }
+}
+
+impl MdxOptions {
+    fn to_cmark(self) -> pulldown_cmark::Options {
+        let mut options = pulldown_cmark::Options::empty();
+        options.set(pulldown_cmark::Options::ENABLE_TABLES, self.tables);
+        options.set(
+            pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+            self.strikethrough,
+        );
+        options.set(pulldown_cmark::Options::ENABLE_FOOTNOTES, self.footnotes);
+        options.set(pulldown_cmark::Options::ENABLE_TASKLISTS, self.task_lists);
+        options.set(
+            pulldown_cmark::Options::ENABLE_HEADING_ATTRIBUTES,
+            self.heading_attributes,
+        );
+        options
+    }
+}
 
 #[component]
 /// Renders a markdown source into a Leptos component.
 /// Custom components can be used in the markdown source.
-pub fn Mdx(source: String, components: Components) -> impl IntoView {
-    let (_fm, html) = parse(&source).expect("invalid mdx");
-    // TODO: we could expose frontmatter in the context so components can use its value
+///
+/// `source` is a [`MaybeSignal`], so it accepts either a plain `String` or a
+/// reactive signal. When a signal is passed the document is re-parsed and the
+/// view tree rebuilt every time it changes, which is what live-preview editors
+/// and `.mdx` file watchers rely on.
+pub fn Mdx(
+    #[prop(into)] source: MaybeSignal<String>,
+    components: Components,
+    /// Called with each [`MdxError`]. For frontmatter and markdown errors its
+    /// view is rendered above the recovered document; for an HTML parse error
+    /// it replaces the document in place of the default inline placeholder.
+    #[prop(optional, into)]
+    on_error: Option<Callback<MdxError, View>>,
+    /// GitHub-Flavored-Markdown extensions to enable while parsing.
+    #[prop(optional)]
+    options: MdxOptions,
+    /// Opt into `femark`'s tree-sitter pipeline so fenced code blocks are
+    /// syntax-highlighted. When enabled the generated table of contents is
+    /// exposed through `use_context::<MdxTableOfContents>()`.
+    #[prop(optional)]
+    highlight: bool,
+) -> impl IntoView {
+    let cmark = options.to_cmark();
+
+    // Cache the parsed frontmatter + HTML so the markdown pipeline only re-runs
+    // when the source actually changes. Frontmatter errors are recovered here by
+    // treating the whole input as body markdown.
+    let parsed = create_memo(move |_| {
+        let source = source.get();
+        if highlight {
+            match parse_highlighted(&source) {
+                Ok((fm, html, toc)) => (fm, html, toc, None),
+                // Fall back to the plain pipeline so a highlighter failure still
+                // renders the document.
+                Err(error) => match parse(&source, cmark) {
+                    Ok((fm, html)) => (fm, html, None, Some(MdxError::Markdown(error.to_string()))),
+                    Err(error) => (
+                        None,
+                        md_to_html(&source, cmark),
+                        None,
+                        Some(MdxError::Frontmatter(error.to_string())),
+                    ),
+                },
+            }
+        } else {
+            match parse(&source, cmark) {
+                Ok((fm, html)) => (fm, html, None, None),
+                Err(error) => (
+                    None,
+                    md_to_html(&source, cmark),
+                    None,
+                    Some(MdxError::Frontmatter(error.to_string())),
+                ),
+            }
+        }
+    });
+
+    move || {
+        let (fm, html, toc, error) = parsed.get();
+
+        // Expose the frontmatter and table of contents so registered components
+        // can read document metadata through the Leptos context.
+        provide_context(MdxFrontmatter::new(fm));
+        provide_context(MdxTableOfContents::new(toc));
+        provide_context(components.handlers());
+
+        // A failed HTML parse is the one non-recoverable case: there is no valid
+        // document left to render, so the error view replaces it entirely.
+        let dom = match tl::parse(&html, tl::ParserOptions::default()) {
+            Ok(dom) => dom,
+            Err(error) => {
+                let error = MdxError::Html(error.to_string());
+                let view = match on_error {
+                    Some(on_error) => on_error.call(error),
+                    None => error_placeholder(&error.to_string()),
+                };
+                return Fragment::new(vec![view]);
+            }
+        };
 
-    let dom = tl::parse(&html, tl::ParserOptions::default()).expect("invalid html");
+        let mut root_views = vec![];
 
-    let mut root_views = vec![];
-    for node_handle in dom.children() {
-        let node = node_handle.get(dom.parser()).expect("not a node");
-        root_views.push(process_element(node, dom.parser(), &components, true));
+        // Frontmatter/markdown errors were recovered in the memo, so the body
+        // still renders; surface the error non-destructively above it.
+        if let (Some(error), Some(on_error)) = (error, on_error) {
+            root_views.push(on_error.call(error));
+        }
+
+        for node_handle in dom.children() {
+            match node_handle.get(dom.parser()) {
+                Some(node) => {
+                    root_views.push(process_element(node, dom.parser(), &components, true))
+                }
+                // Keep rendering the remaining siblings instead of aborting.
+                None => root_views.push(error_placeholder("invalid node")),
+            }
+        }
+
+        Fragment::new(root_views)
     }
+}
+
+/// A minimal inline placeholder emitted when a single node fails to convert, so
+/// the rest of the document still renders.
+fn error_placeholder(message: &str) -> View {
+    leptos::html::span()
+        .attr("class", "mdx-error")
+        .child(message.to_string())
+        .into_view()
+}
 
-    Fragment::new(root_views)
+/// The table of contents produced by the `femark` highlighting pipeline, made
+/// available to registered components through the Leptos context. It is empty
+/// unless the `highlight` prop is enabled and the document produced a TOC.
+#[derive(Clone, Default)]
+pub struct MdxTableOfContents {
+    html: Option<String>,
+}
+
+impl MdxTableOfContents {
+    fn new(html: Option<String>) -> Self {
+        Self { html }
+    }
+
+    /// The table of contents as an HTML string, if one was generated.
+    pub fn html(&self) -> Option<&str> {
+        self.html.as_deref()
+    }
+}
+
+/// Document metadata parsed from the leading YAML frontmatter block, made
+/// available to registered components through the Leptos context.
+///
+/// Components read it with `use_context::<MdxFrontmatter>()` and reach for the
+/// typed accessors instead of matching on the raw [`frontmatter::Yaml`] enum:
+///
+/// ```ignore
+/// let fm = use_context::<MdxFrontmatter>().unwrap_or_default();
+/// let title = fm.get_string("title");
+/// let tags = fm.get_sequence("tags");
+/// ```
+#[derive(Clone, Default)]
+pub struct MdxFrontmatter {
+    yaml: Option<frontmatter::Yaml>,
+}
+
+impl MdxFrontmatter {
+    fn new(yaml: Option<frontmatter::Yaml>) -> Self {
+        Self { yaml }
+    }
+
+    /// The raw parsed YAML, if the document had a frontmatter block.
+    pub fn yaml(&self) -> Option<&frontmatter::Yaml> {
+        self.yaml.as_ref()
+    }
+
+    /// Look up a top-level key, returning the raw node.
+    pub fn get(&self, key: &str) -> Option<&frontmatter::Yaml> {
+        match self.yaml.as_ref()? {
+            frontmatter::Yaml::Hash(hash) => {
+                hash.get(&frontmatter::Yaml::String(key.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Read a top-level key as a string (e.g. `title`, `date`).
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.get(key)?.as_str().map(|s| s.to_string())
+    }
+
+    /// Read a top-level key as an integer.
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key)?.as_i64()
+    }
+
+    /// Read a top-level key as a boolean.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.as_bool()
+    }
+
+    /// Read a top-level sequence as a list of strings (e.g. `tags`).
+    pub fn get_sequence(&self, key: &str) -> Option<Vec<String>> {
+        let seq = self.get(key)?.as_vec()?;
+        Some(
+            seq.iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect(),
+        )
+    }
 }
 
 /// Props passed to a custom component.
@@ -30,18 +289,40 @@ pub struct MdxComponentProps {
     pub id: Option<String>,
     pub classes: Vec<String>,
     pub attributes: HashMap<String, Option<String>>,
+    /// Event directives authored as `on:<event>="<handler>"` attributes, keyed
+    /// by event name (`click`) with the registered handler name as the value
+    /// (`increment`). A component adapter resolves the handler name through
+    /// [`MdxHandlers`] to wire a delegated DOM listener.
+    pub events: HashMap<String, String>,
     pub children: Children,
 }
 
+/// The `Fn()` callbacks registered through [`Components::add_handler`], made
+/// available to component adapters through the Leptos context so `on:`-prefixed
+/// directives can be bound to real DOM event listeners.
+#[derive(Clone, Default)]
+pub struct MdxHandlers {
+    handlers: Rc<HashMap<String, Rc<dyn Fn()>>>,
+}
+
+impl MdxHandlers {
+    /// Resolve a handler by the name used in an `on:<event>="<name>"` directive.
+    pub fn get(&self, name: &str) -> Option<Rc<dyn Fn()>> {
+        self.handlers.get(name).cloned()
+    }
+}
+
 /// A collection of custom components.
 pub struct Components {
     components: HashMap<String, Box<dyn Fn(MdxComponentProps) -> View>>,
+    handlers: HashMap<String, Rc<dyn Fn()>>,
 }
 
 impl Components {
     pub fn new() -> Self {
         Self {
             components: HashMap::new(),
+            handlers: HashMap::new(),
         }
     }
 
@@ -74,9 +355,26 @@ impl Components {
         );
     }
 
+    /// Register a named event handler that authored `on:<event>="<name>"`
+    /// directives can bind to. The callback is resolved by its `name` through
+    /// the [`MdxHandlers`] context inside a component adapter.
+    pub fn add_handler<F>(&mut self, name: String, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        self.handlers.insert(name, Rc::new(handler));
+    }
+
     fn get(&self, name: &str) -> Option<&Box<dyn Fn(MdxComponentProps) -> View>> {
         self.components.get(name)
     }
+
+    /// Snapshot the registered handlers for provision through context.
+    fn handlers(&self) -> MdxHandlers {
+        MdxHandlers {
+            handlers: Rc::new(self.handlers.clone()),
+        }
+    }
 }
 
 pub fn process_element(
@@ -112,168 +410,216 @@ pub fn process_element(
             }
         }
         Node::Tag(tag) => {
-            let mut child_views = vec![];
-
-            let nodes = tag.children();
-
-            // Process children
-            nodes.top().iter().for_each(|node_handle| {
-                let node = node_handle.get(parser).expect("not a node");
-
-                /*
-                 * Inside code blocks we want to keep the new lines as they are.
-                 */
-                if tag.name().as_utf8_str() == "code" || tag.name().as_utf8_str() == "pre" {
-                    child_views.push(process_element(node, parser, components, false));
-                } else {
-                    child_views.push(process_element(node, parser, components, parse_new_lines));
-                }
-            });
-
             let name_ref = tag.name().as_utf8_str();
             let name = name_ref.as_ref();
 
             // Custom elements
             if let Some(component) = components.get(name) {
+                let mut child_views = vec![];
+
+                let nodes = tag.children();
+
+                // Process children
+                nodes.top().iter().for_each(|node_handle| {
+                    let Some(node) = node_handle.get(parser) else {
+                        child_views.push(error_placeholder("invalid node"));
+                        return;
+                    };
+
+                    /*
+                     * Inside code blocks we want to keep the new lines as they are.
+                     */
+                    if name == "code" || name == "pre" {
+                        child_views.push(process_element(node, parser, components, false));
+                    } else {
+                        child_views.push(process_element(node, parser, components, parse_new_lines));
+                    }
+                });
+
                 let attributes = tag.attributes();
 
                 let classes = attributes.class_iter().map_or(Vec::new(), |class_list| {
                     class_list.map(|c| c.to_string()).collect()
                 });
 
-                let attributes_map = attributes
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.map(|v| v.to_string())))
-                    .collect();
+                // Split the `on:<event>="<handler>"` directives out of the plain
+                // attribute map so components can wire them to real handlers.
+                let mut attributes_map = HashMap::new();
+                let mut events = HashMap::new();
+                for (key, value) in attributes.iter() {
+                    if let Some(event) = key.strip_prefix("on:") {
+                        if let Some(value) = value {
+                            events.insert(event.to_string(), value.to_string());
+                        }
+                    } else {
+                        attributes_map.insert(key.to_string(), value.map(|v| v.to_string()));
+                    }
+                }
 
                 return component(MdxComponentProps {
                     id: attributes.id().map(|id| id.as_utf8_str().to_string()),
                     classes,
                     attributes: attributes_map,
+                    events,
                     children: Box::new(move || Fragment::new(child_views)),
                 });
             }
 
+            /*
+             * Inert fast-path: if neither this element nor any of its descendants
+             * references a registered component, the whole subtree is static. We
+             * serialize it back to an HTML string once and hand it to a single
+             * element via `inner_html`, instead of rebuilding a `View` per node.
+             */
+            let inert_inner = if tag_contains_component(tag, parser, components) {
+                None
+            } else {
+                Some(serialize_children(tag, parser, parse_new_lines))
+            };
+
+            let mut child_views = vec![];
+
+            if inert_inner.is_none() {
+                let nodes = tag.children();
+
+                // Process children
+                nodes.top().iter().for_each(|node_handle| {
+                    let Some(node) = node_handle.get(parser) else {
+                        child_views.push(error_placeholder("invalid node"));
+                        return;
+                    };
+
+                    /*
+                     * Inside code blocks we want to keep the new lines as they are.
+                     */
+                    if name == "code" || name == "pre" {
+                        child_views.push(process_element(node, parser, components, false));
+                    } else {
+                        child_views.push(process_element(node, parser, components, parse_new_lines));
+                    }
+                });
+            }
+
             // HTML elements
             match name {
-                "html" => html_element(&tag.clone(), child_views, leptos::html::html()),
-                "base" => html_element(&tag.clone(), child_views, leptos::html::base()),
-                "head" => html_element(&tag.clone(), child_views, leptos::html::head()),
-                "link" => html_element(&tag.clone(), child_views, leptos::html::link()),
-                "meta" => html_element(&tag.clone(), child_views, leptos::html::meta()),
-                "style" => html_element(&tag.clone(), child_views, leptos::html::style()),
-                "title" => html_element(&tag.clone(), child_views, leptos::html::title()),
-                "body" => html_element(&tag.clone(), child_views, leptos::html::body()),
-                "address" => html_element(&tag.clone(), child_views, leptos::html::address()),
-                "article" => html_element(&tag.clone(), child_views, leptos::html::article()),
-                "aside" => html_element(&tag.clone(), child_views, leptos::html::aside()),
-                "footer" => html_element(&tag.clone(), child_views, leptos::html::footer()),
-                "header" => html_element(&tag.clone(), child_views, leptos::html::header()),
-                "hgroup" => html_element(&tag.clone(), child_views, leptos::html::hgroup()),
-                "h1" => html_element(&tag.clone(), child_views, leptos::html::h1()),
-                "h2" => html_element(&tag.clone(), child_views, leptos::html::h2()),
-                "h3" => html_element(&tag.clone(), child_views, leptos::html::h3()),
-                "h4" => html_element(&tag.clone(), child_views, leptos::html::h4()),
-                "h5" => html_element(&tag.clone(), child_views, leptos::html::h5()),
-                "h6" => html_element(&tag.clone(), child_views, leptos::html::h6()),
-                "main" => html_element(&tag.clone(), child_views, leptos::html::main()),
-                "nav" => html_element(&tag.clone(), child_views, leptos::html::nav()),
-                "section" => html_element(&tag.clone(), child_views, leptos::html::section()),
-                "blockquote" => html_element(&tag.clone(), child_views, leptos::html::blockquote()),
-                "dd" => html_element(&tag.clone(), child_views, leptos::html::dd()),
-                "div" => html_element(&tag.clone(), child_views, leptos::html::div()),
-                "dl" => html_element(&tag.clone(), child_views, leptos::html::dl()),
-                "dt" => html_element(&tag.clone(), child_views, leptos::html::dt()),
-                "figcaption" => html_element(&tag.clone(), child_views, leptos::html::figcaption()),
-                "figure" => html_element(&tag.clone(), child_views, leptos::html::figure()),
-                "hr" => html_element(&tag.clone(), child_views, leptos::html::hr()),
-                "li" => html_element(&tag.clone(), child_views, leptos::html::li()),
-                "ol" => html_element(&tag.clone(), child_views, leptos::html::ol()),
-                "p" => html_element(&tag.clone(), child_views, leptos::html::p()),
-                "pre" => html_element(&tag.clone(), child_views, leptos::html::pre()),
-                "ul" => html_element(&tag.clone(), child_views, leptos::html::ul()),
-                "a" => html_element(&tag.clone(), child_views, leptos::html::a()),
-                "abbr" => html_element(&tag.clone(), child_views, leptos::html::abbr()),
-                "b" => html_element(&tag.clone(), child_views, leptos::html::b()),
-                "bdi" => html_element(&tag.clone(), child_views, leptos::html::bdi()),
-                "bdo" => html_element(&tag.clone(), child_views, leptos::html::bdo()),
-                "br" => html_element(&tag.clone(), child_views, leptos::html::br()),
-                "cite" => html_element(&tag.clone(), child_views, leptos::html::cite()),
-                "code" => html_element(&tag.clone(), child_views, leptos::html::code()),
-                "data" => html_element(&tag.clone(), child_views, leptos::html::data()),
-                "dfn" => html_element(&tag.clone(), child_views, leptos::html::dfn()),
-                "em" => html_element(&tag.clone(), child_views, leptos::html::em()),
-                "i" => html_element(&tag.clone(), child_views, leptos::html::i()),
-                "kbd" => html_element(&tag.clone(), child_views, leptos::html::kbd()),
-                "mark" => html_element(&tag.clone(), child_views, leptos::html::mark()),
-                "q" => html_element(&tag.clone(), child_views, leptos::html::q()),
-                "rp" => html_element(&tag.clone(), child_views, leptos::html::rp()),
-                "rt" => html_element(&tag.clone(), child_views, leptos::html::rt()),
-                "ruby" => html_element(&tag.clone(), child_views, leptos::html::ruby()),
-                "s" => html_element(&tag.clone(), child_views, leptos::html::s()),
-                "samp" => html_element(&tag.clone(), child_views, leptos::html::samp()),
-                "small" => html_element(&tag.clone(), child_views, leptos::html::small()),
-                "span" => html_element(&tag.clone(), child_views, leptos::html::span()),
-                "strong" => html_element(&tag.clone(), child_views, leptos::html::strong()),
-                "sub" => html_element(&tag.clone(), child_views, leptos::html::sub()),
-                "sup" => html_element(&tag.clone(), child_views, leptos::html::sup()),
-                "time" => html_element(&tag.clone(), child_views, leptos::html::time()),
-                "u" => html_element(&tag.clone(), child_views, leptos::html::u()),
-                "var" => html_element(&tag.clone(), child_views, leptos::html::var()),
-                "wbr" => html_element(&tag.clone(), child_views, leptos::html::wbr()),
-                "area" => html_element(&tag.clone(), child_views, leptos::html::area()),
-                "audio" => html_element(&tag.clone(), child_views, leptos::html::audio()),
-                "img" => html_element(&tag.clone(), child_views, leptos::html::img()),
-                "map" => html_element(&tag.clone(), child_views, leptos::html::map()),
-                "track" => html_element(&tag.clone(), child_views, leptos::html::track()),
-                "video" => html_element(&tag.clone(), child_views, leptos::html::video()),
-                "embed" => html_element(&tag.clone(), child_views, leptos::html::embed()),
-                "iframe" => html_element(&tag.clone(), child_views, leptos::html::iframe()),
-                "object" => html_element(&tag.clone(), child_views, leptos::html::object()),
-                "param" => html_element(&tag.clone(), child_views, leptos::html::param()),
-                "picture" => html_element(&tag.clone(), child_views, leptos::html::picture()),
-                "portal" => html_element(&tag.clone(), child_views, leptos::html::portal()),
-                "source" => html_element(&tag.clone(), child_views, leptos::html::source()),
-                "svg" => html_element(&tag.clone(), child_views, leptos::html::svg()),
-                "math" => html_element(&tag.clone(), child_views, leptos::html::math()),
-                "canvas" => html_element(&tag.clone(), child_views, leptos::html::canvas()),
-                "noscript" => html_element(&tag.clone(), child_views, leptos::html::noscript()),
-                "script" => html_element(&tag.clone(), child_views, leptos::html::script()),
-                "del" => html_element(&tag.clone(), child_views, leptos::html::del()),
-                "ins" => html_element(&tag.clone(), child_views, leptos::html::ins()),
-                "caption" => html_element(&tag.clone(), child_views, leptos::html::caption()),
-                "col" => html_element(&tag.clone(), child_views, leptos::html::col()),
-                "colgroup" => html_element(&tag.clone(), child_views, leptos::html::colgroup()),
-                "table" => html_element(&tag.clone(), child_views, leptos::html::table()),
-                "tbody" => html_element(&tag.clone(), child_views, leptos::html::tbody()),
-                "td" => html_element(&tag.clone(), child_views, leptos::html::td()),
-                "tfoot" => html_element(&tag.clone(), child_views, leptos::html::tfoot()),
-                "th" => html_element(&tag.clone(), child_views, leptos::html::th()),
-                "thead" => html_element(&tag.clone(), child_views, leptos::html::thead()),
-                "tr" => html_element(&tag.clone(), child_views, leptos::html::tr()),
-                "button" => html_element(&tag.clone(), child_views, leptos::html::button()),
-                "datalist" => html_element(&tag.clone(), child_views, leptos::html::datalist()),
-                "fieldset" => html_element(&tag.clone(), child_views, leptos::html::fieldset()),
-                "form" => html_element(&tag.clone(), child_views, leptos::html::form()),
-                "input" => html_element(&tag.clone(), child_views, leptos::html::input()),
-                "label" => html_element(&tag.clone(), child_views, leptos::html::label()),
-                "legend" => html_element(&tag.clone(), child_views, leptos::html::legend()),
-                "meter" => html_element(&tag.clone(), child_views, leptos::html::meter()),
-                "optgroup" => html_element(&tag.clone(), child_views, leptos::html::optgroup()),
-                "option" => html_element(&tag.clone(), child_views, leptos::html::option()),
-                "output" => html_element(&tag.clone(), child_views, leptos::html::output()),
-                "progress" => html_element(&tag.clone(), child_views, leptos::html::progress()),
-                "select" => html_element(&tag.clone(), child_views, leptos::html::select()),
-                "textarea" => html_element(&tag.clone(), child_views, leptos::html::textarea()),
-                "details" => html_element(&tag.clone(), child_views, leptos::html::details()),
-                "dialog" => html_element(&tag.clone(), child_views, leptos::html::dialog()),
-                "menu" => html_element(&tag.clone(), child_views, leptos::html::menu()),
-                "summary" => html_element(&tag.clone(), child_views, leptos::html::summary()),
-                "slot" => html_element(&tag.clone(), child_views, leptos::html::slot()),
-                "template" => html_element(&tag.clone(), child_views, leptos::html::template()),
+                "html" => html_element(&tag.clone(), child_views, leptos::html::html(), inert_inner),
+                "base" => html_element(&tag.clone(), child_views, leptos::html::base(), inert_inner),
+                "head" => html_element(&tag.clone(), child_views, leptos::html::head(), inert_inner),
+                "link" => html_element(&tag.clone(), child_views, leptos::html::link(), inert_inner),
+                "meta" => html_element(&tag.clone(), child_views, leptos::html::meta(), inert_inner),
+                "style" => html_element(&tag.clone(), child_views, leptos::html::style(), inert_inner),
+                "title" => html_element(&tag.clone(), child_views, leptos::html::title(), inert_inner),
+                "body" => html_element(&tag.clone(), child_views, leptos::html::body(), inert_inner),
+                "address" => html_element(&tag.clone(), child_views, leptos::html::address(), inert_inner),
+                "article" => html_element(&tag.clone(), child_views, leptos::html::article(), inert_inner),
+                "aside" => html_element(&tag.clone(), child_views, leptos::html::aside(), inert_inner),
+                "footer" => html_element(&tag.clone(), child_views, leptos::html::footer(), inert_inner),
+                "header" => html_element(&tag.clone(), child_views, leptos::html::header(), inert_inner),
+                "hgroup" => html_element(&tag.clone(), child_views, leptos::html::hgroup(), inert_inner),
+                "h1" => html_element(&tag.clone(), child_views, leptos::html::h1(), inert_inner),
+                "h2" => html_element(&tag.clone(), child_views, leptos::html::h2(), inert_inner),
+                "h3" => html_element(&tag.clone(), child_views, leptos::html::h3(), inert_inner),
+                "h4" => html_element(&tag.clone(), child_views, leptos::html::h4(), inert_inner),
+                "h5" => html_element(&tag.clone(), child_views, leptos::html::h5(), inert_inner),
+                "h6" => html_element(&tag.clone(), child_views, leptos::html::h6(), inert_inner),
+                "main" => html_element(&tag.clone(), child_views, leptos::html::main(), inert_inner),
+                "nav" => html_element(&tag.clone(), child_views, leptos::html::nav(), inert_inner),
+                "section" => html_element(&tag.clone(), child_views, leptos::html::section(), inert_inner),
+                "blockquote" => html_element(&tag.clone(), child_views, leptos::html::blockquote(), inert_inner),
+                "dd" => html_element(&tag.clone(), child_views, leptos::html::dd(), inert_inner),
+                "div" => html_element(&tag.clone(), child_views, leptos::html::div(), inert_inner),
+                "dl" => html_element(&tag.clone(), child_views, leptos::html::dl(), inert_inner),
+                "dt" => html_element(&tag.clone(), child_views, leptos::html::dt(), inert_inner),
+                "figcaption" => html_element(&tag.clone(), child_views, leptos::html::figcaption(), inert_inner),
+                "figure" => html_element(&tag.clone(), child_views, leptos::html::figure(), inert_inner),
+                "hr" => html_element(&tag.clone(), child_views, leptos::html::hr(), inert_inner),
+                "li" => html_element(&tag.clone(), child_views, leptos::html::li(), inert_inner),
+                "ol" => html_element(&tag.clone(), child_views, leptos::html::ol(), inert_inner),
+                "p" => html_element(&tag.clone(), child_views, leptos::html::p(), inert_inner),
+                "pre" => html_element(&tag.clone(), child_views, leptos::html::pre(), inert_inner),
+                "ul" => html_element(&tag.clone(), child_views, leptos::html::ul(), inert_inner),
+                "a" => html_element(&tag.clone(), child_views, leptos::html::a(), inert_inner),
+                "abbr" => html_element(&tag.clone(), child_views, leptos::html::abbr(), inert_inner),
+                "b" => html_element(&tag.clone(), child_views, leptos::html::b(), inert_inner),
+                "bdi" => html_element(&tag.clone(), child_views, leptos::html::bdi(), inert_inner),
+                "bdo" => html_element(&tag.clone(), child_views, leptos::html::bdo(), inert_inner),
+                "br" => html_element(&tag.clone(), child_views, leptos::html::br(), inert_inner),
+                "cite" => html_element(&tag.clone(), child_views, leptos::html::cite(), inert_inner),
+                "code" => html_element(&tag.clone(), child_views, leptos::html::code(), inert_inner),
+                "data" => html_element(&tag.clone(), child_views, leptos::html::data(), inert_inner),
+                "dfn" => html_element(&tag.clone(), child_views, leptos::html::dfn(), inert_inner),
+                "em" => html_element(&tag.clone(), child_views, leptos::html::em(), inert_inner),
+                "i" => html_element(&tag.clone(), child_views, leptos::html::i(), inert_inner),
+                "kbd" => html_element(&tag.clone(), child_views, leptos::html::kbd(), inert_inner),
+                "mark" => html_element(&tag.clone(), child_views, leptos::html::mark(), inert_inner),
+                "q" => html_element(&tag.clone(), child_views, leptos::html::q(), inert_inner),
+                "rp" => html_element(&tag.clone(), child_views, leptos::html::rp(), inert_inner),
+                "rt" => html_element(&tag.clone(), child_views, leptos::html::rt(), inert_inner),
+                "ruby" => html_element(&tag.clone(), child_views, leptos::html::ruby(), inert_inner),
+                "s" => html_element(&tag.clone(), child_views, leptos::html::s(), inert_inner),
+                "samp" => html_element(&tag.clone(), child_views, leptos::html::samp(), inert_inner),
+                "small" => html_element(&tag.clone(), child_views, leptos::html::small(), inert_inner),
+                "span" => html_element(&tag.clone(), child_views, leptos::html::span(), inert_inner),
+                "strong" => html_element(&tag.clone(), child_views, leptos::html::strong(), inert_inner),
+                "sub" => html_element(&tag.clone(), child_views, leptos::html::sub(), inert_inner),
+                "sup" => html_element(&tag.clone(), child_views, leptos::html::sup(), inert_inner),
+                "time" => html_element(&tag.clone(), child_views, leptos::html::time(), inert_inner),
+                "u" => html_element(&tag.clone(), child_views, leptos::html::u(), inert_inner),
+                "var" => html_element(&tag.clone(), child_views, leptos::html::var(), inert_inner),
+                "wbr" => html_element(&tag.clone(), child_views, leptos::html::wbr(), inert_inner),
+                "area" => html_element(&tag.clone(), child_views, leptos::html::area(), inert_inner),
+                "audio" => html_element(&tag.clone(), child_views, leptos::html::audio(), inert_inner),
+                "img" => html_element(&tag.clone(), child_views, leptos::html::img(), inert_inner),
+                "map" => html_element(&tag.clone(), child_views, leptos::html::map(), inert_inner),
+                "track" => html_element(&tag.clone(), child_views, leptos::html::track(), inert_inner),
+                "video" => html_element(&tag.clone(), child_views, leptos::html::video(), inert_inner),
+                "embed" => html_element(&tag.clone(), child_views, leptos::html::embed(), inert_inner),
+                "iframe" => html_element(&tag.clone(), child_views, leptos::html::iframe(), inert_inner),
+                "object" => html_element(&tag.clone(), child_views, leptos::html::object(), inert_inner),
+                "param" => html_element(&tag.clone(), child_views, leptos::html::param(), inert_inner),
+                "picture" => html_element(&tag.clone(), child_views, leptos::html::picture(), inert_inner),
+                "portal" => html_element(&tag.clone(), child_views, leptos::html::portal(), inert_inner),
+                "source" => html_element(&tag.clone(), child_views, leptos::html::source(), inert_inner),
+                "svg" => html_element(&tag.clone(), child_views, leptos::html::svg(), inert_inner),
+                "math" => html_element(&tag.clone(), child_views, leptos::html::math(), inert_inner),
+                "canvas" => html_element(&tag.clone(), child_views, leptos::html::canvas(), inert_inner),
+                "noscript" => html_element(&tag.clone(), child_views, leptos::html::noscript(), inert_inner),
+                "script" => html_element(&tag.clone(), child_views, leptos::html::script(), inert_inner),
+                "del" => html_element(&tag.clone(), child_views, leptos::html::del(), inert_inner),
+                "ins" => html_element(&tag.clone(), child_views, leptos::html::ins(), inert_inner),
+                "caption" => html_element(&tag.clone(), child_views, leptos::html::caption(), inert_inner),
+                "col" => html_element(&tag.clone(), child_views, leptos::html::col(), inert_inner),
+                "colgroup" => html_element(&tag.clone(), child_views, leptos::html::colgroup(), inert_inner),
+                "table" => html_element(&tag.clone(), child_views, leptos::html::table(), inert_inner),
+                "tbody" => html_element(&tag.clone(), child_views, leptos::html::tbody(), inert_inner),
+                "td" => html_element(&tag.clone(), child_views, leptos::html::td(), inert_inner),
+                "tfoot" => html_element(&tag.clone(), child_views, leptos::html::tfoot(), inert_inner),
+                "th" => html_element(&tag.clone(), child_views, leptos::html::th(), inert_inner),
+                "thead" => html_element(&tag.clone(), child_views, leptos::html::thead(), inert_inner),
+                "tr" => html_element(&tag.clone(), child_views, leptos::html::tr(), inert_inner),
+                "button" => html_element(&tag.clone(), child_views, leptos::html::button(), inert_inner),
+                "datalist" => html_element(&tag.clone(), child_views, leptos::html::datalist(), inert_inner),
+                "fieldset" => html_element(&tag.clone(), child_views, leptos::html::fieldset(), inert_inner),
+                "form" => html_element(&tag.clone(), child_views, leptos::html::form(), inert_inner),
+                "input" => html_element(&tag.clone(), child_views, leptos::html::input(), inert_inner),
+                "label" => html_element(&tag.clone(), child_views, leptos::html::label(), inert_inner),
+                "legend" => html_element(&tag.clone(), child_views, leptos::html::legend(), inert_inner),
+                "meter" => html_element(&tag.clone(), child_views, leptos::html::meter(), inert_inner),
+                "optgroup" => html_element(&tag.clone(), child_views, leptos::html::optgroup(), inert_inner),
+                "option" => html_element(&tag.clone(), child_views, leptos::html::option(), inert_inner),
+                "output" => html_element(&tag.clone(), child_views, leptos::html::output(), inert_inner),
+                "progress" => html_element(&tag.clone(), child_views, leptos::html::progress(), inert_inner),
+                "select" => html_element(&tag.clone(), child_views, leptos::html::select(), inert_inner),
+                "textarea" => html_element(&tag.clone(), child_views, leptos::html::textarea(), inert_inner),
+                "details" => html_element(&tag.clone(), child_views, leptos::html::details(), inert_inner),
+                "dialog" => html_element(&tag.clone(), child_views, leptos::html::dialog(), inert_inner),
+                "menu" => html_element(&tag.clone(), child_views, leptos::html::menu(), inert_inner),
+                "summary" => html_element(&tag.clone(), child_views, leptos::html::summary(), inert_inner),
+                "slot" => html_element(&tag.clone(), child_views, leptos::html::slot(), inert_inner),
+                "template" => html_element(&tag.clone(), child_views, leptos::html::template(), inert_inner),
                 _ => {
                     println!("unknown element {}", name);
-                    ().into_view()
+                    error_placeholder(&format!("unknown element {name}"))
                 }
             }
         }
@@ -284,6 +630,7 @@ fn html_element<Element>(
     element: &HTMLTag,
     children: Vec<View>,
     mut leptos_el: HtmlElement<Element>,
+    inner_html: Option<String>,
 ) -> View
 where
     Element: ElementDescriptor + 'static,
@@ -315,9 +662,127 @@ where
         leptos_el = leptos_el.attr("class", classes.join(" "));
     }
 
+    // For an inert subtree we skip the per-child `View`s entirely and set the
+    // pre-serialized HTML string in one shot.
+    if let Some(inner) = inner_html {
+        return leptos_el.inner_html(inner).into_view();
+    }
+
     for child in children {
         leptos_el = leptos_el.child(child);
     }
 
     leptos_el.into_view()
 }
+
+/// Returns `true` when any descendant of `tag` references a registered custom
+/// component and therefore still needs the recursive builder path. The name of
+/// `tag` itself is not checked.
+fn tag_contains_component(tag: &HTMLTag, parser: &tl::Parser, components: &Components) -> bool {
+    tag.children().top().iter().any(|node_handle| {
+        match node_handle.get(parser) {
+            Some(Node::Tag(child)) => {
+                components.get(child.name().as_utf8_str().as_ref()).is_some()
+                    || tag_contains_component(child, parser, components)
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Serialize the children of an inert `tag` back into an HTML string, applying
+/// the same `<br/>` newline substitution as the recursive path and keeping the
+/// verbatim newlines inside `code`/`pre` blocks.
+fn serialize_children(tag: &HTMLTag, parser: &tl::Parser, parse_new_lines: bool) -> String {
+    // Inside code blocks we want to keep the new lines as they are, including
+    // the direct children of this very tag (matching the recursive path).
+    let name = tag.name().as_utf8_str();
+    let parse_new_lines = parse_new_lines && name != "code" && name != "pre";
+
+    let mut out = String::new();
+    for node_handle in tag.children().top().iter() {
+        if let Some(node) = node_handle.get(parser) {
+            serialize_node(node, parser, parse_new_lines, &mut out);
+        }
+    }
+    out
+}
+
+/// Escape text so it round-trips through `inner_html` as the same escaped text
+/// node the recursive path produces via `String::into_view`.
+fn escape_text(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Escape an attribute value, additionally guarding the `"` delimiter.
+fn escape_attr(s: &str) -> String {
+    escape_text(s).replace('"', "&quot;")
+}
+
+/// Void elements are emitted self-closing so serialization round-trips cleanly.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+fn serialize_node(node: &Node, parser: &tl::Parser, parse_new_lines: bool, out: &mut String) {
+    match node {
+        Node::Comment(_) => {}
+        Node::Raw(raw) => {
+            let text = raw.as_utf8_str();
+
+            if parse_new_lines {
+                /*
+                 * Replace new lines with <br /> only if they are preceded and followed by text.
+                 * to avoid adding <br /> to empty lines.
+                 */
+                let reg = Regex::new(r"(.+)\n(.+)").unwrap();
+
+                let text = reg.replace_all(&text, |caps: &regex::Captures| {
+                    format!("{} <br /> {}", &caps[1], &caps[2])
+                });
+
+                out.push_str(&escape_text(&text));
+            } else {
+                out.push_str(&escape_text(&text));
+            }
+        }
+        Node::Tag(tag) => {
+            let name_ref = tag.name().as_utf8_str();
+            let name = name_ref.as_ref();
+
+            out.push('<');
+            out.push_str(name);
+            for (key, value) in tag.attributes().iter() {
+                out.push(' ');
+                out.push_str(&key);
+                if let Some(value) = value {
+                    out.push_str("=\"");
+                    out.push_str(&escape_attr(&value));
+                    out.push('"');
+                }
+            }
+
+            if VOID_ELEMENTS.contains(&name) {
+                out.push_str("/>");
+                return;
+            }
+
+            out.push('>');
+
+            // Inside code blocks we want to keep the new lines as they are.
+            let parse_new_lines = parse_new_lines && name != "code" && name != "pre";
+            for child in tag.children().top().iter() {
+                if let Some(child) = child.get(parser) {
+                    serialize_node(child, parser, parse_new_lines, out);
+                }
+            }
+
+            out.push_str("</");
+            out.push_str(name);
+            out.push('>');
+        }
+    }
+}